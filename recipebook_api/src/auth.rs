@@ -0,0 +1,96 @@
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredKey {
+    salt: String,
+    hash: String,
+}
+
+/// Issued API keys, persisted as salted blake3 hashes. The plaintext key is
+/// never stored; `issue_key` returns it once, at creation time.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ApiKeyStore {
+    keys: Vec<StoredKey>,
+}
+
+impl ApiKeyStore {
+    /// Loads the store from `path`, or starts an empty store if the file
+    /// doesn't exist yet.
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let contents =
+            serde_json::to_string_pretty(self).expect("ApiKeyStore always serializes");
+        fs::write(path, contents)
+    }
+
+    /// Generates a new random API key, storing only its salted hash, and
+    /// returns the plaintext so it can be shown to the caller once.
+    pub fn issue_key(&mut self) -> String {
+        let plaintext = to_hex(&random_bytes::<32>());
+        let salt = to_hex(&random_bytes::<16>());
+
+        self.keys.push(StoredKey {
+            hash: hash_with_salt(&plaintext, &salt),
+            salt,
+        });
+
+        plaintext
+    }
+
+    /// Validates a plaintext key against every stored salted hash.
+    pub fn verify(&self, plaintext: &str) -> bool {
+        self.keys
+            .iter()
+            .any(|key| hash_with_salt(plaintext, &key.salt) == key.hash)
+    }
+}
+
+fn random_bytes<const N: usize>() -> [u8; N] {
+    let mut bytes = [0u8; N];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    bytes
+}
+
+fn hash_with_salt(plaintext: &str, salt: &str) -> String {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(salt.as_bytes());
+    hasher.update(plaintext.as_bytes());
+    hasher.finalize().to_hex().to_string()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issued_key_verifies() {
+        let mut store = ApiKeyStore::default();
+        let key = store.issue_key();
+        assert!(store.verify(&key));
+        assert!(!store.verify("not-the-key"));
+    }
+
+    #[test]
+    fn test_distinct_keys_get_distinct_salts() {
+        let mut store = ApiKeyStore::default();
+        let first = store.issue_key();
+        let second = store.issue_key();
+        assert_ne!(first, second);
+        assert_ne!(store.keys[0].salt, store.keys[1].salt);
+        assert!(store.verify(&first));
+        assert!(store.verify(&second));
+    }
+}
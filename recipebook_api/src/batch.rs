@@ -0,0 +1,32 @@
+use crate::cancellable::CancelHandle;
+use crate::db::Db;
+use crate::llm_utils::RecipeInfo;
+use crate::pipeline::{scrape_and_extract, PipelineError};
+use futures::stream::{self, StreamExt};
+use std::time::Duration;
+
+/// Scrapes many URLs concurrently, bounded by `concurrency`, collecting a
+/// per-URL result so that one bad URL doesn't abort the whole batch. Each
+/// URL gets its own `CancelHandle`, so cancelling one in-flight scrape
+/// never affects the others.
+pub async fn scrape_batch(
+    urls: Vec<String>,
+    api_key: &str,
+    db: &Db,
+    concurrency: usize,
+    timeout: Duration,
+) -> Vec<(String, Result<RecipeInfo, PipelineError>)> {
+    // `buffer_unordered(0)` places no limit at all on concurrency, so an
+    // unvalidated 0 from a caller is raised to 1 to keep the bound.
+    let concurrency = concurrency.max(1);
+
+    stream::iter(urls)
+        .map(|url| async move {
+            let cancel_handle = CancelHandle::new();
+            let result = scrape_and_extract(&url, Some(api_key), db, timeout, &cancel_handle).await;
+            (url, result)
+        })
+        .buffer_unordered(concurrency)
+        .collect()
+        .await
+}
@@ -0,0 +1,139 @@
+use futures::future::{AbortHandle, Abortable, Aborted};
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Wraps an operation's own error with the two ways `run_cancellable` can
+/// end a future early, so callers can tell "the server was slow" apart
+/// from "I cancelled it myself".
+#[derive(Debug)]
+pub enum CancelError<E> {
+    TimedOut(Duration),
+    Aborted,
+    Failed(E),
+}
+
+impl<E: fmt::Display> fmt::Display for CancelError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CancelError::TimedOut(duration) => {
+                write!(f, "timed out after {}s", duration.as_secs())
+            }
+            CancelError::Aborted => write!(f, "aborted by caller"),
+            CancelError::Failed(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for CancelError<E> {}
+
+/// A clonable handle that can abort an in-flight `run_cancellable` call.
+/// Any holder of a clone can cancel the work it guards.
+#[derive(Clone, Default)]
+pub struct CancelHandle(Arc<Mutex<Option<AbortHandle>>>);
+
+impl CancelHandle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Aborts the guarded operation, if one is running. No caller in this
+    /// crate invokes it; the HTTP server does not call it when a client
+    /// disconnects.
+    #[allow(dead_code)]
+    pub fn abort(&self) {
+        if let Some(handle) = self.0.lock().unwrap().take() {
+            handle.abort();
+        }
+    }
+
+    fn set(&self, handle: AbortHandle) {
+        *self.0.lock().unwrap() = Some(handle);
+    }
+}
+
+/// Runs `future` to completion unless it exceeds `timeout` (a zero
+/// duration disables the timeout) or `cancel_handle.abort()` is called
+/// first, in which case the operation stops and a `CancelError` is
+/// returned instead of its result.
+pub async fn run_cancellable<T, E, F>(
+    future: F,
+    timeout: Duration,
+    cancel_handle: &CancelHandle,
+) -> Result<T, CancelError<E>>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    let (abort_handle, abort_registration) = AbortHandle::new_pair();
+    cancel_handle.set(abort_handle);
+    let abortable = Abortable::new(future, abort_registration);
+
+    let abortable_result = if timeout.is_zero() {
+        abortable.await
+    } else {
+        match tokio::time::timeout(timeout, abortable).await {
+            Ok(result) => result,
+            Err(_elapsed) => return Err(CancelError::TimedOut(timeout)),
+        }
+    };
+
+    match abortable_result {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(e)) => Err(CancelError::Failed(e)),
+        Err(Aborted) => Err(CancelError::Aborted),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_times_out() {
+        let handle = CancelHandle::new();
+        let result: Result<(), CancelError<()>> = run_cancellable(
+            async {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok(())
+            },
+            Duration::from_millis(5),
+            &handle,
+        )
+        .await;
+
+        assert!(matches!(result, Err(CancelError::TimedOut(_))));
+    }
+
+    #[tokio::test]
+    async fn test_zero_timeout_disables_it() {
+        let handle = CancelHandle::new();
+        let result: Result<&str, CancelError<()>> =
+            run_cancellable(async { Ok("done") }, Duration::ZERO, &handle).await;
+
+        assert!(matches!(result, Ok("done")));
+    }
+
+    #[tokio::test]
+    async fn test_abort_stops_the_future() {
+        let handle = CancelHandle::new();
+        let abort_handle = handle.clone();
+
+        let run = run_cancellable(
+            async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok::<(), ()>(())
+            },
+            Duration::from_secs(5),
+            &handle,
+        );
+
+        let aborter = async {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            abort_handle.abort();
+        };
+
+        let (result, _) = tokio::join!(run, aborter);
+        assert!(matches!(result, Err(CancelError::Aborted)));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::llm_utils::RecipeInfo;
+use clap::{Parser, Subcommand, ValueEnum};
+
+#[derive(Debug, Parser)]
+#[command(name = "recipebook", about = "Scrape and extract recipe data")]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Command,
+
+    /// Per-operation timeout in seconds, applied to each scrape and LLM
+    /// call; 0 disables the timeout
+    #[arg(long, global = true, default_value_t = 30)]
+    pub timeout_secs: u64,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Scrape a single recipe from a live URL
+    ScrapUrl {
+        url: String,
+        #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+        format: OutputFormat,
+    },
+    /// Parse a recipe from a previously saved HTML file, without any network call
+    ScrapFile {
+        path: String,
+        #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+        format: OutputFormat,
+    },
+    /// Scrape every URL listed (one per line) in a text file, concurrently
+    ScrapList {
+        list_path: String,
+        #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+        format: OutputFormat,
+        /// Maximum number of URLs to scrape at once
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u64).range(1..))]
+        concurrency: u64,
+    },
+    /// Start an HTTP API server exposing scrape/extract over `/api/v1`
+    Serve {
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+        /// Path to the JSON file of issued API key hashes
+        #[arg(long, default_value = "api_keys.json")]
+        keys_file: String,
+    },
+    /// Issue a new API key, printing the plaintext once
+    IssueApiKey {
+        #[arg(long, default_value = "api_keys.json")]
+        keys_file: String,
+    },
+    /// Re-scrape a URL list on a cron schedule, keeping the store current
+    Cron {
+        /// Cron expression, e.g. "0 0 */6 * * *" for every 6 hours
+        cron_expr: String,
+        list_path: String,
+        /// Maximum number of URLs to scrape at once per run
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u64).range(1..))]
+        concurrency: u64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    Json,
+    Pretty,
+    Csv,
+}
+
+/// Renders a single `RecipeInfo` according to the requested `OutputFormat`.
+pub fn format_recipe_info(recipe_info: &RecipeInfo, format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_string(recipe_info).unwrap_or_else(|e| format!("{{\"error\": \"{}\"}}", e))
+        }
+        OutputFormat::Pretty => serde_json::to_string_pretty(recipe_info)
+            .unwrap_or_else(|e| format!("error serializing recipe: {}", e)),
+        OutputFormat::Csv => recipe_info_to_csv(recipe_info),
+    }
+}
+
+fn recipe_info_to_csv(recipe_info: &RecipeInfo) -> String {
+    let header = "ingredients,prep_time,cook_time,total_time,servings";
+    let row = format!(
+        "\"{}\",{},{},{},{}",
+        recipe_info.ingredients.join("; ").replace('"', "\"\""),
+        recipe_info.prep_time.as_deref().unwrap_or(""),
+        recipe_info.cook_time.as_deref().unwrap_or(""),
+        recipe_info.total_time.as_deref().unwrap_or(""),
+        recipe_info.servings.as_deref().unwrap_or(""),
+    );
+    format!("{}\n{}", header, row)
+}
@@ -0,0 +1,198 @@
+use crate::llm_utils::RecipeInfo;
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Default location for the persistent recipe store.
+pub const DEFAULT_DB_PATH: &str = "recipes.db";
+
+/// SQLite-backed store of previously scraped recipes, keyed by URL.
+/// `rusqlite::Connection` is `Send` but not `Sync`, so the connection is
+/// kept behind a `Mutex` to let `Db` be shared across concurrent callers
+/// (e.g. axum handlers, or batch/cron tasks) without each one opening its
+/// own connection.
+pub struct Db {
+    conn: Mutex<Connection>,
+}
+
+/// Mirrors a full `recipes` row; only some fields are read by current
+/// callers, but the rest are kept for callers that want the raw stored page.
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct StoredRecipe {
+    pub url: String,
+    pub title: String,
+    pub recipe_info: RecipeInfo,
+    pub html: String,
+    pub content_hash: String,
+    pub scraped_at: String,
+}
+
+impl Db {
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS recipes (
+                url TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                ingredients TEXT NOT NULL,
+                prep_time TEXT,
+                cook_time TEXT,
+                total_time TEXT,
+                servings TEXT,
+                html TEXT NOT NULL,
+                content_hash TEXT NOT NULL,
+                scraped_at TEXT NOT NULL
+            )",
+            [],
+        )?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Inserts a freshly scraped recipe, or overwrites the existing row for
+    /// that URL, stamping `scraped_at` with the current time.
+    pub fn upsert_recipe(
+        &self,
+        url: &str,
+        title: &str,
+        recipe_info: &RecipeInfo,
+        html: &str,
+        content_hash: &str,
+    ) -> rusqlite::Result<()> {
+        let ingredients_json = serde_json::to_string(&recipe_info.ingredients)
+            .expect("Vec<String> always serializes");
+
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO recipes
+                (url, title, ingredients, prep_time, cook_time, total_time, servings, html, content_hash, scraped_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+             ON CONFLICT(url) DO UPDATE SET
+                title = excluded.title,
+                ingredients = excluded.ingredients,
+                prep_time = excluded.prep_time,
+                cook_time = excluded.cook_time,
+                total_time = excluded.total_time,
+                servings = excluded.servings,
+                html = excluded.html,
+                content_hash = excluded.content_hash,
+                scraped_at = excluded.scraped_at",
+            params![
+                url,
+                title,
+                ingredients_json,
+                recipe_info.prep_time,
+                recipe_info.cook_time,
+                recipe_info.total_time,
+                recipe_info.servings,
+                html,
+                content_hash,
+                Utc::now().to_rfc3339(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Bumps `scraped_at` to now without touching any other column, so a
+    /// cache hit (unchanged content) still counts as "just checked" for
+    /// callers like cron that need the store to stay current.
+    pub fn touch_scraped_at(&self, url: &str) -> rusqlite::Result<()> {
+        self.conn.lock().unwrap().execute(
+            "UPDATE recipes SET scraped_at = ?1 WHERE url = ?2",
+            params![Utc::now().to_rfc3339(), url],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_by_url(&self, url: &str) -> rusqlite::Result<Option<StoredRecipe>> {
+        self.conn
+            .lock()
+            .unwrap()
+            .query_row(
+                "SELECT url, title, ingredients, prep_time, cook_time, total_time, servings, html, content_hash, scraped_at
+                 FROM recipes WHERE url = ?1",
+                params![url],
+                row_to_stored_recipe,
+            )
+            .optional()
+    }
+
+    pub fn list(&self) -> rusqlite::Result<Vec<StoredRecipe>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT url, title, ingredients, prep_time, cook_time, total_time, servings, html, content_hash, scraped_at
+             FROM recipes ORDER BY scraped_at DESC",
+        )?;
+        let rows = stmt.query_map([], row_to_stored_recipe)?.collect();
+        rows
+    }
+}
+
+fn row_to_stored_recipe(row: &Row) -> rusqlite::Result<StoredRecipe> {
+    let ingredients_json: String = row.get(2)?;
+    let ingredients: Vec<String> = serde_json::from_str(&ingredients_json).unwrap_or_default();
+
+    Ok(StoredRecipe {
+        url: row.get(0)?,
+        title: row.get(1)?,
+        recipe_info: RecipeInfo {
+            ingredients,
+            prep_time: row.get(3)?,
+            cook_time: row.get(4)?,
+            total_time: row.get(5)?,
+            servings: row.get(6)?,
+        },
+        html: row.get(7)?,
+        content_hash: row.get(8)?,
+        scraped_at: row.get(9)?,
+    })
+}
+
+/// Hashes normalized page content so re-scrapes of unchanged pages can skip
+/// the LLM extraction step entirely.
+pub fn hash_content(content: &str) -> String {
+    blake3::hash(content.trim().as_bytes()).to_hex().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_recipe() -> RecipeInfo {
+        RecipeInfo {
+            ingredients: vec!["1 cup flour".to_string()],
+            prep_time: Some("15m".to_string()),
+            cook_time: Some("1h".to_string()),
+            total_time: Some("1h 15m".to_string()),
+            servings: Some("4".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_upsert_and_get_by_url() {
+        let db = Db::open(":memory:").unwrap();
+        let recipe = sample_recipe();
+        db.upsert_recipe("https://example.com/r", "Example", &recipe, "<html></html>", "abc123")
+            .unwrap();
+
+        let stored = db.get_by_url("https://example.com/r").unwrap().unwrap();
+        assert_eq!(stored.title, "Example");
+        assert_eq!(stored.content_hash, "abc123");
+        assert_eq!(stored.recipe_info.ingredients, vec!["1 cup flour"]);
+    }
+
+    #[test]
+    fn test_get_by_url_missing() {
+        let db = Db::open(":memory:").unwrap();
+        assert!(db.get_by_url("https://example.com/missing").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_hash_content_is_stable() {
+        assert_eq!(hash_content("  same content  "), hash_content("same content"));
+        assert_ne!(hash_content("a"), hash_content("b"));
+    }
+}
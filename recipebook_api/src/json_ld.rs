@@ -0,0 +1,184 @@
+use crate::llm_utils::RecipeInfo;
+use scraper::{Html, Selector};
+use serde_json::Value;
+
+/// Looks for a schema.org `Recipe` object embedded in a page's JSON-LD
+/// `<script>` tags and maps it onto `RecipeInfo`. Returns `None` if no
+/// `<script type="application/ld+json">` block contains a `Recipe` node,
+/// so callers can fall back to LLM-based extraction.
+pub fn extract_recipe_from_jsonld(html: &str) -> Option<RecipeInfo> {
+    let document = Html::parse_document(html);
+    let selector = Selector::parse(r#"script[type="application/ld+json"]"#).ok()?;
+
+    document.select(&selector).find_map(|script| {
+        let raw = script.text().collect::<String>();
+        let value: Value = serde_json::from_str(&raw).ok()?;
+        let recipe = find_recipe_node(&value)?;
+        Some(recipe_info_from_jsonld(recipe))
+    })
+}
+
+/// Finds a `Recipe` node in a JSON-LD value, which may be a bare object,
+/// a top-level array of objects, or an `@graph` array of objects.
+fn find_recipe_node(value: &Value) -> Option<&Value> {
+    match value {
+        Value::Object(_) if is_recipe(value) => Some(value),
+        Value::Object(_) => value.get("@graph").and_then(find_recipe_node),
+        Value::Array(items) => items.iter().find_map(find_recipe_node),
+        _ => None,
+    }
+}
+
+fn is_recipe(value: &Value) -> bool {
+    match value.get("@type") {
+        Some(Value::String(t)) => t == "Recipe",
+        Some(Value::Array(types)) => types.iter().any(|t| t.as_str() == Some("Recipe")),
+        _ => false,
+    }
+}
+
+fn recipe_info_from_jsonld(recipe: &Value) -> RecipeInfo {
+    let ingredients = recipe
+        .get("recipeIngredient")
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|s| s.trim().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    RecipeInfo {
+        ingredients,
+        prep_time: recipe.get("prepTime").and_then(Value::as_str).map(humanize_duration),
+        cook_time: recipe.get("cookTime").and_then(Value::as_str).map(humanize_duration),
+        total_time: recipe.get("totalTime").and_then(Value::as_str).map(humanize_duration),
+        servings: recipe.get("recipeYield").and_then(yield_to_string),
+    }
+}
+
+fn yield_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.trim().to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Array(items) => items.first().and_then(yield_to_string),
+        _ => None,
+    }
+}
+
+/// Parses an ISO-8601 duration like `PT1H30M` into `"1h 30m"`. Falls back
+/// to the original string if it isn't a recognized `PT#H#M#S` duration.
+fn humanize_duration(duration: &str) -> String {
+    let Some(time_part) = duration.strip_prefix("PT") else {
+        return duration.to_string();
+    };
+
+    let mut hours = 0u32;
+    let mut minutes = 0u32;
+    let mut seconds = 0u32;
+    let mut number = String::new();
+
+    for c in time_part.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'H' => {
+                hours = number.parse().unwrap_or(0);
+                number.clear();
+            }
+            'M' => {
+                minutes = number.parse().unwrap_or(0);
+                number.clear();
+            }
+            'S' => {
+                seconds = number.parse().unwrap_or(0);
+                number.clear();
+            }
+            _ => {}
+        }
+    }
+
+    let mut parts = Vec::new();
+    if hours > 0 {
+        parts.push(format!("{}h", hours));
+    }
+    if minutes > 0 {
+        parts.push(format!("{}m", minutes));
+    }
+    if seconds > 0 {
+        parts.push(format!("{}s", seconds));
+    }
+
+    if parts.is_empty() {
+        duration.to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_humanize_duration() {
+        assert_eq!(humanize_duration("PT1H30M"), "1h 30m");
+        assert_eq!(humanize_duration("PT45M"), "45m");
+        assert_eq!(humanize_duration("PT2H"), "2h");
+        assert_eq!(humanize_duration("not-a-duration"), "not-a-duration");
+    }
+
+    #[test]
+    fn test_extract_recipe_from_jsonld_bare_object() {
+        let html = r#"
+            <html><head>
+            <script type="application/ld+json">
+            {
+                "@type": "Recipe",
+                "recipeIngredient": ["1 cup flour", "2 eggs"],
+                "prepTime": "PT15M",
+                "cookTime": "PT1H",
+                "recipeYield": "4 servings"
+            }
+            </script>
+            </head><body></body></html>
+        "#;
+
+        let info = extract_recipe_from_jsonld(html).expect("expected a recipe");
+        assert_eq!(info.ingredients, vec!["1 cup flour", "2 eggs"]);
+        assert_eq!(info.prep_time.as_deref(), Some("15m"));
+        assert_eq!(info.cook_time.as_deref(), Some("1h"));
+        assert_eq!(info.servings.as_deref(), Some("4 servings"));
+    }
+
+    #[test]
+    fn test_extract_recipe_from_jsonld_graph() {
+        let html = r#"
+            <html><head>
+            <script type="application/ld+json">
+            {
+                "@graph": [
+                    {"@type": "WebSite"},
+                    {"@type": "Recipe", "recipeIngredient": ["sugar"]}
+                ]
+            }
+            </script>
+            </head><body></body></html>
+        "#;
+
+        let info = extract_recipe_from_jsonld(html).expect("expected a recipe");
+        assert_eq!(info.ingredients, vec!["sugar"]);
+    }
+
+    #[test]
+    fn test_extract_recipe_from_jsonld_no_recipe() {
+        let html = r#"
+            <html><head>
+            <script type="application/ld+json">{"@type": "WebSite"}</script>
+            </head><body></body></html>
+        "#;
+
+        assert!(extract_recipe_from_jsonld(html).is_none());
+    }
+}
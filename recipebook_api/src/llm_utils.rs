@@ -1,4 +1,3 @@
-use reqwest;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -8,6 +7,7 @@ pub enum LlmError {
     ApiError(String),
     ParseError(String),
     InvalidResponse,
+    MissingApiKey,
 }
 
 impl fmt::Display for LlmError {
@@ -17,6 +17,7 @@ impl fmt::Display for LlmError {
             LlmError::ApiError(msg) => write!(f, "API error: {}", msg),
             LlmError::ParseError(msg) => write!(f, "Parse error: {}", msg),
             LlmError::InvalidResponse => write!(f, "Invalid response from API"),
+            LlmError::MissingApiKey => write!(f, "OpenRouter API key not set"),
         }
     }
 }
@@ -51,7 +52,7 @@ struct ChatResponse {
     choices: Vec<ChatChoice>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct RecipeInfo {
     pub ingredients: Vec<String>,
     pub prep_time: Option<String>,
@@ -1,37 +1,124 @@
+mod auth;
+mod batch;
+mod cancellable;
+mod cli;
+mod db;
+mod json_ld;
 mod llm_utils;
+mod pipeline;
 mod recipe_scraper;
+mod retry;
+mod scheduler;
+mod server;
 
-use llm_utils::extract_recipe_info;
-use recipe_scraper::scrape_webpage;
+use auth::ApiKeyStore;
+use batch::scrape_batch;
+use cancellable::CancelHandle;
+use cli::{format_recipe_info, Args, Command};
+use clap::Parser;
+use db::{Db, DEFAULT_DB_PATH};
+use recipe_scraper::parse_html;
 use std::env;
+use std::fs;
+use std::time::Duration;
 
 #[tokio::main]
-async fn main() -> Result<(), Box<recipe_scraper::ScraperError>> {
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Load environment variables from .env file
     dotenvy::dotenv().ok();
 
-    let api_key =
-        env::var("OPENROUTER_API_KEY").expect("OPENROUTER_API_KEY must be set in .env file");
+    let args = Args::parse();
+    let timeout = Duration::from_secs(args.timeout_secs);
+    let db = Db::open(DEFAULT_DB_PATH)?;
 
-    let url = "https://www.recipetineats.com/classic-lamingtons";
+    match args.command {
+        Command::ScrapUrl { url, format } => {
+            println!("Scraping recipe from: {}", url);
+            let api_key = env::var("OPENROUTER_API_KEY")
+                .expect("OPENROUTER_API_KEY must be set in .env file");
+            let cancel_handle = CancelHandle::new();
+            let page = pipeline::fetch_page(&url, timeout, &cancel_handle).await?;
+            let recipe_info =
+                pipeline::extract_with_cache(&page, Some(&api_key), &db, timeout, &cancel_handle)
+                    .await?;
+            println!("{}", format_recipe_info(&recipe_info, format));
+        }
+        Command::ScrapFile { path, format } => {
+            let html_content = fs::read_to_string(&path)?;
+            let page = parse_html(&html_content, &path)?;
+            // Deferred: a file with a JSON-LD recipe needs no LLM call at
+            // all, so don't require the API key until the LLM fallback
+            // actually runs.
+            let api_key = env::var("OPENROUTER_API_KEY").ok();
+            let cancel_handle = CancelHandle::new();
+            let recipe_info = pipeline::extract_with_cache(
+                &page,
+                api_key.as_deref(),
+                &db,
+                timeout,
+                &cancel_handle,
+            )
+            .await?;
+            println!("{}", format_recipe_info(&recipe_info, format));
+        }
+        Command::ScrapList {
+            list_path,
+            format,
+            concurrency,
+        } => {
+            let list_content = fs::read_to_string(&list_path)?;
+            let urls: Vec<String> = list_content
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(str::to_string)
+                .collect();
 
-    println!("Scraping recipe from: {}", url);
+            let api_key = env::var("OPENROUTER_API_KEY")
+                .expect("OPENROUTER_API_KEY must be set in .env file");
 
-    let page = match scrape_webpage(url).await {
-        Ok(page) => {
-            println!("✓ Successfully scraped: {}", page.title);
-            page
+            let results = scrape_batch(urls, &api_key, &db, concurrency as usize, timeout).await;
+            for (url, result) in results {
+                match result {
+                    Ok(recipe_info) => {
+                        println!("✓ {}", url);
+                        println!("{}", format_recipe_info(&recipe_info, format));
+                    }
+                    Err(e) => eprintln!("✗ {}: {}", url, e),
+                }
+            }
         }
-        Err(e) => {
-            eprintln!("✗ Scraping error: {}", e);
-            return Err(Box::new(e));
+        Command::Serve { port, keys_file } => {
+            let api_keys = ApiKeyStore::load(&keys_file);
+            let api_key = env::var("OPENROUTER_API_KEY")
+                .expect("OPENROUTER_API_KEY must be set in .env file");
+            server::serve(port, db, api_keys, api_key, timeout).await?;
         }
-    };
+        Command::IssueApiKey { keys_file } => {
+            let mut api_keys = ApiKeyStore::load(&keys_file);
+            let new_key = api_keys.issue_key();
+            api_keys.save(&keys_file)?;
+            println!("New API key (save this, it will not be shown again):\n{}", new_key);
+        }
+        Command::Cron {
+            cron_expr,
+            list_path,
+            concurrency,
+        } => {
+            let list_content = fs::read_to_string(&list_path)?;
+            let urls: Vec<String> = list_content
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(str::to_string)
+                .collect();
 
-    println!("\nExtracting recipe information with LLM...");
+            let api_key = env::var("OPENROUTER_API_KEY")
+                .expect("OPENROUTER_API_KEY must be set in .env file");
 
-    let recipe_info_result = extract_recipe_info(&page.content, &api_key).await;
-    println!("Recipe Information:\n{:?}", recipe_info_result);
+            scheduler::run_schedule(&cron_expr, urls, &api_key, &db, concurrency as usize, timeout).await?;
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,96 @@
+use crate::cancellable::{run_cancellable, CancelError, CancelHandle};
+use crate::db::{hash_content, Db};
+use crate::json_ld::extract_recipe_from_jsonld;
+use crate::llm_utils::{extract_recipe_info, LlmError, RecipeInfo};
+use crate::recipe_scraper::{scrape_webpage, ScraperError, WebPage};
+use crate::retry::with_retry;
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub enum PipelineError {
+    Scraper(CancelError<ScraperError>),
+    Llm(CancelError<LlmError>),
+}
+
+impl fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PipelineError::Scraper(e) => write!(f, "{}", e),
+            PipelineError::Llm(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for PipelineError {}
+
+/// Fetches `url`, retrying transient network failures and bounded by
+/// `timeout` (zero disables it); cancellable early through `cancel_handle`.
+pub async fn fetch_page(
+    url: &str,
+    timeout: Duration,
+    cancel_handle: &CancelHandle,
+) -> Result<WebPage, CancelError<ScraperError>> {
+    with_retry(|| run_cancellable(scrape_webpage(url), timeout, cancel_handle)).await
+}
+
+/// Extracts recipe info from an already-fetched page: JSON-LD first, then
+/// a cached result if the page's content hasn't changed since it was last
+/// stored, and only then the LLM-backed extractor, retried and bounded by
+/// `timeout`. Persists the result to `db` either way, bumping `scraped_at`
+/// even on a cache hit so repeated callers like cron see the store as
+/// current. Shared by every caller (single-URL, batch, cron, and the HTTP
+/// API) so the extraction order and caching behavior can't drift between
+/// them.
+///
+/// `api_key` is only required if neither the JSON-LD nor the cache check
+/// resolves the recipe, so a caller without one (e.g. offline `scrap-file`
+/// use) still works as long as one of those fast paths hits.
+pub async fn extract_with_cache(
+    page: &WebPage,
+    api_key: Option<&str>,
+    db: &Db,
+    timeout: Duration,
+    cancel_handle: &CancelHandle,
+) -> Result<RecipeInfo, CancelError<LlmError>> {
+    let content_hash = hash_content(&page.content);
+
+    if let Some(recipe_info) = extract_recipe_from_jsonld(&page.html) {
+        let _ = db.upsert_recipe(&page.url, &page.title, &recipe_info, &page.html, &content_hash);
+        return Ok(recipe_info);
+    }
+
+    if let Ok(Some(stored)) = db.get_by_url(&page.url) {
+        if stored.content_hash == content_hash {
+            let _ = db.touch_scraped_at(&page.url);
+            return Ok(stored.recipe_info);
+        }
+    }
+
+    let api_key = api_key.ok_or(CancelError::Failed(LlmError::MissingApiKey))?;
+
+    let recipe_info = with_retry(|| {
+        run_cancellable(extract_recipe_info(&page.content, api_key), timeout, cancel_handle)
+    })
+    .await?;
+
+    let _ = db.upsert_recipe(&page.url, &page.title, &recipe_info, &page.html, &content_hash);
+    Ok(recipe_info)
+}
+
+/// Scrapes a single URL end-to-end: fetch then extract, as above.
+pub async fn scrape_and_extract(
+    url: &str,
+    api_key: Option<&str>,
+    db: &Db,
+    timeout: Duration,
+    cancel_handle: &CancelHandle,
+) -> Result<RecipeInfo, PipelineError> {
+    let page = fetch_page(url, timeout, cancel_handle)
+        .await
+        .map_err(PipelineError::Scraper)?;
+
+    extract_with_cache(&page, api_key, db, timeout, cancel_handle)
+        .await
+        .map_err(PipelineError::Llm)
+}
@@ -1,4 +1,3 @@
-use reqwest;
 use scraper::{Html, Selector};
 use std::fmt;
 
@@ -7,7 +6,6 @@ pub enum ScraperError {
     NetworkError(reqwest::Error),
     InvalidUrl(String),
     ParseError(String),
-    TimeoutError,
     EmptyContent,
 }
 
@@ -17,7 +15,6 @@ impl fmt::Display for ScraperError {
             ScraperError::NetworkError(e) => write!(f, "Network error: {}", e),
             ScraperError::InvalidUrl(url) => write!(f, "Invalid URL: {}", url),
             ScraperError::ParseError(msg) => write!(f, "Parse error: {}", msg),
-            ScraperError::TimeoutError => write!(f, "Request timeout"),
             ScraperError::EmptyContent => write!(f, "No content found"),
         }
     }
@@ -44,22 +41,12 @@ pub async fn scrape_webpage(url: &str) -> Result<WebPage, ScraperError> {
         return Err(ScraperError::InvalidUrl("URL cannot be empty".to_string()));
     }
 
-    let client = reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(30))
-        .build()
-        .map_err(|e| ScraperError::NetworkError(e))?;
+    // Cancellation, including timeouts, is handled uniformly by
+    // `cancellable::run_cancellable` at the call site, not per-client here.
+    let client = reqwest::Client::new();
 
-    let response = client.get(url).send().await;
-
-    match response {
-        Ok(resp) => parse_response(resp, url).await,
-        Err(err) if err.is_timeout() => {
-            return Err(ScraperError::TimeoutError);
-        }
-        Err(err) => {
-            return Err(ScraperError::NetworkError(err));
-        }
-    }
+    let response = client.get(url).send().await?;
+    parse_response(response, url).await
 }
 
 pub async fn parse_response(
@@ -67,12 +54,18 @@ pub async fn parse_response(
     url: &str,
 ) -> Result<WebPage, ScraperError> {
     let html_content = response.text().await?;
+    parse_html(&html_content, url)
+}
 
+/// Parses raw HTML into a `WebPage`, independent of how it was obtained.
+/// Shared by `parse_response` (network) and callers that already have HTML
+/// on disk, e.g. the `scrap-file` CLI subcommand.
+pub fn parse_html(html_content: &str, url: &str) -> Result<WebPage, ScraperError> {
     if html_content.trim().is_empty() {
         return Err(ScraperError::EmptyContent);
     }
 
-    let document = Html::parse_document(&html_content);
+    let document = Html::parse_document(html_content);
 
     let title_selector =
         Selector::parse("title").map_err(|e| ScraperError::ParseError(e.to_string()))?;
@@ -88,21 +81,16 @@ pub async fn parse_response(
         .select(&body_selector)
         .next()
         .map(|el| el.text().collect::<String>())
-        .unwrap_or_else(|| html_content.clone());
+        .unwrap_or_else(|| html_content.to_string());
 
     Ok(WebPage {
         url: url.to_string(),
         title,
         content,
-        html: html_content,
+        html: html_content.to_string(),
     })
 }
 
-pub async fn get_webpage_content(url: &str) -> Result<String, ScraperError> {
-    let page = scrape_webpage(url).await?;
-    Ok(page.content)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,10 +100,4 @@ mod tests {
         let result = scrape_webpage("").await;
         assert!(matches!(result, Err(ScraperError::InvalidUrl(_))));
     }
-
-    #[tokio::test]
-    async fn test_empty_url() {
-        let result = get_webpage_content("").await;
-        assert!(result.is_err());
-    }
 }
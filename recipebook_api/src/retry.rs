@@ -0,0 +1,115 @@
+use crate::cancellable::CancelError;
+use crate::llm_utils::LlmError;
+use crate::recipe_scraper::ScraperError;
+use rand::Rng;
+use std::future::Future;
+use std::time::Duration;
+
+const MAX_RETRIES: u32 = 5;
+const BASE_DELAY_MS: u64 = 300;
+
+/// Errors that know whether retrying them is worthwhile, so the retry
+/// helper doesn't waste attempts on e.g. a malformed URL.
+pub trait Retryable {
+    fn is_retryable(&self) -> bool;
+}
+
+impl Retryable for ScraperError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, ScraperError::NetworkError(_))
+    }
+}
+
+impl Retryable for LlmError {
+    fn is_retryable(&self) -> bool {
+        match self {
+            LlmError::NetworkError(_) => true,
+            LlmError::ApiError(message) => is_retryable_status(message),
+            LlmError::ParseError(_) | LlmError::InvalidResponse | LlmError::MissingApiKey => false,
+        }
+    }
+}
+
+/// A timeout is itself worth retrying (the next attempt gets a fresh
+/// clock); an explicit abort never is.
+impl<E: Retryable> Retryable for CancelError<E> {
+    fn is_retryable(&self) -> bool {
+        match self {
+            CancelError::TimedOut(_) => true,
+            CancelError::Aborted => false,
+            CancelError::Failed(e) => e.is_retryable(),
+        }
+    }
+}
+
+/// `LlmError::ApiError` messages are formatted as `"HTTP {status}: {body}"`,
+/// where `{status}` is a `reqwest::StatusCode`'s `Display`, e.g.
+/// `"429 Too Many Requests"` — so the numeric code is its first word, not
+/// everything before the colon. Only 429 and 5xx responses are worth
+/// retrying.
+fn is_retryable_status(message: &str) -> bool {
+    message
+        .strip_prefix("HTTP ")
+        .and_then(|rest| rest.split_whitespace().next())
+        .and_then(|code| code.parse::<u16>().ok())
+        .is_some_and(|code| code == 429 || (500..600).contains(&code))
+}
+
+/// Retries `operation` up to `MAX_RETRIES` times with exponential backoff
+/// and jitter, starting at `BASE_DELAY_MS`, but only for errors that report
+/// themselves as `Retryable`.
+pub async fn with_retry<T, E, F, Fut>(mut operation: F) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: Retryable,
+{
+    let mut attempt = 0;
+    loop {
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt < MAX_RETRIES && err.is_retryable() => {
+                tokio::time::sleep(backoff_delay(attempt)).await;
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+fn backoff_delay(attempt: u32) -> Duration {
+    let base_ms = BASE_DELAY_MS * 2u64.pow(attempt);
+    let jitter_ms = rand::thread_rng().gen_range(0..=base_ms / 2);
+    Duration::from_millis(base_ms + jitter_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retryable_status_codes() {
+        assert!(is_retryable_status("HTTP 429 Too Many Requests: rate limited"));
+        assert!(is_retryable_status(
+            "HTTP 503 Service Unavailable: service unavailable"
+        ));
+        assert!(!is_retryable_status("HTTP 404 Not Found: not found"));
+        assert!(!is_retryable_status("not an http error"));
+    }
+
+    #[test]
+    fn test_scraper_error_retryable() {
+        assert!(!ScraperError::InvalidUrl("bad".to_string()).is_retryable());
+        assert!(!ScraperError::ParseError("bad".to_string()).is_retryable());
+        assert!(!ScraperError::EmptyContent.is_retryable());
+    }
+
+    #[test]
+    fn test_cancel_error_timeout_is_always_retryable() {
+        let timed_out: CancelError<ScraperError> = CancelError::TimedOut(Duration::from_secs(1));
+        assert!(timed_out.is_retryable());
+
+        let aborted: CancelError<ScraperError> = CancelError::Aborted;
+        assert!(!aborted.is_retryable());
+    }
+}
@@ -0,0 +1,42 @@
+use crate::batch::scrape_batch;
+use crate::db::Db;
+use chrono::Utc;
+use cron::Schedule;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Re-runs the batch scrape pipeline at each fire time of `cron_expr`,
+/// looping until the schedule has no further fire times, so the local store
+/// stays current without manual invocation. Intended for long-running daemon use via the `cron`
+/// subcommand.
+pub async fn run_schedule(
+    cron_expr: &str,
+    urls: Vec<String>,
+    api_key: &str,
+    db: &Db,
+    concurrency: usize,
+    timeout: Duration,
+) -> Result<(), cron::error::Error> {
+    let schedule = Schedule::from_str(cron_expr)?;
+
+    loop {
+        let now = Utc::now();
+        let Some(next_fire) = schedule.after(&now).next() else {
+            println!("Cron schedule has no further fire times, stopping");
+            return Ok(());
+        };
+
+        let until_next = (next_fire - now).to_std().unwrap_or(Duration::ZERO);
+        println!("Next scrape scheduled for {}", next_fire);
+        tokio::time::sleep(until_next).await;
+
+        println!("Running scheduled scrape for {} URL(s)", urls.len());
+        let results = scrape_batch(urls.clone(), api_key, db, concurrency, timeout).await;
+        for (url, result) in results {
+            match result {
+                Ok(_) => println!("✓ {}", url),
+                Err(e) => eprintln!("✗ {}: {}", url, e),
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::auth::ApiKeyStore;
+use crate::cancellable::CancelHandle;
+use crate::db::Db;
+use crate::pipeline;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode, Uri};
+use axum::response::{IntoResponse, Json};
+use axum::routing::{get, post};
+use axum::Router;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[derive(Clone)]
+struct AppState {
+    db: Arc<Db>,
+    api_keys: Arc<ApiKeyStore>,
+    openrouter_api_key: Arc<String>,
+    request_timeout: Duration,
+}
+
+#[derive(Debug, Deserialize)]
+struct ScrapeRequest {
+    url: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+fn error_response(status: StatusCode, message: impl Into<String>) -> axum::response::Response {
+    (status, Json(ErrorBody { error: message.into() })).into_response()
+}
+
+/// Starts the HTTP API server, serving `/api/v1/scrape` and
+/// `/api/v1/recipes` behind API-key auth.
+pub async fn serve(
+    port: u16,
+    db: Db,
+    api_keys: ApiKeyStore,
+    openrouter_api_key: String,
+    request_timeout: Duration,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let state = AppState {
+        db: Arc::new(db),
+        api_keys: Arc::new(api_keys),
+        openrouter_api_key: Arc::new(openrouter_api_key),
+        request_timeout,
+    };
+
+    let app = Router::new()
+        .route("/api/v1/scrape", post(scrape_handler))
+        .route("/api/v1/recipes", get(list_recipes_handler))
+        .fallback(unknown_route)
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(("0.0.0.0", port)).await?;
+    println!("Listening on http://0.0.0.0:{}", port);
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+/// Checks the `Authorization: Bearer <key>` header against the API key
+/// store, returning `403 Forbidden` when absent or invalid.
+fn authorize(headers: &HeaderMap, api_keys: &ApiKeyStore) -> Result<(), Box<axum::response::Response>> {
+    let forbidden = || Box::new(error_response(StatusCode::FORBIDDEN, "Forbidden"));
+
+    let token = headers
+        .get("authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or_else(forbidden)?;
+
+    if api_keys.verify(token) {
+        Ok(())
+    } else {
+        Err(forbidden())
+    }
+}
+
+async fn scrape_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<ScrapeRequest>,
+) -> axum::response::Response {
+    if let Err(resp) = authorize(&headers, &state.api_keys) {
+        return *resp;
+    }
+
+    let cancel_handle = CancelHandle::new();
+    let page = match pipeline::fetch_page(&payload.url, state.request_timeout, &cancel_handle).await {
+        Ok(page) => page,
+        Err(e) => return error_response(StatusCode::BAD_GATEWAY, e.to_string()),
+    };
+
+    match pipeline::extract_with_cache(
+        &page,
+        Some(&state.openrouter_api_key),
+        &state.db,
+        state.request_timeout,
+        &cancel_handle,
+    )
+    .await
+    {
+        Ok(recipe_info) => Json(recipe_info).into_response(),
+        Err(e) => error_response(StatusCode::BAD_GATEWAY, e.to_string()),
+    }
+}
+
+async fn list_recipes_handler(State(state): State<AppState>, headers: HeaderMap) -> axum::response::Response {
+    if let Err(resp) = authorize(&headers, &state.api_keys) {
+        return *resp;
+    }
+
+    match state.db.list() {
+        Ok(recipes) => {
+            let infos: Vec<_> = recipes.into_iter().map(|r| r.recipe_info).collect();
+            Json(infos).into_response()
+        }
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+}
+
+/// Catches requests that miss both defined routes and reports a distinct
+/// "Unknown API version" error for unrecognized `/api/vN/` prefixes.
+async fn unknown_route(uri: Uri) -> axum::response::Response {
+    if let Some(version) = uri
+        .path()
+        .strip_prefix("/api/")
+        .and_then(|rest| rest.split('/').next())
+        .filter(|segment| segment.starts_with('v'))
+    {
+        if version != "v1" {
+            return error_response(StatusCode::NOT_FOUND, format!("Unknown API version: {}", version));
+        }
+    }
+
+    error_response(StatusCode::NOT_FOUND, "Not found")
+}